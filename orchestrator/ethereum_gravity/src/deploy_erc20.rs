@@ -0,0 +1,189 @@
+use crate::utils::{decode_revert_reason, EthClient};
+use ethers::abi::{encode, Token};
+use ethers::contract::abigen;
+use ethers::types::{Address, Bytes, TransactionReceipt, U256};
+use ethers::utils::{get_create2_address, keccak256};
+use gravity_abi::erc20_representation::erc20_representation_bytecode;
+use gravity_abi::gravity::Gravity;
+use gravity_utils::error::GravityError;
+use std::time::Duration;
+
+abigen!(
+    Create2Deployer,
+    r#"[
+        function deploy(bytes32 salt, bytes initCode) external returns (address)
+    ]"#,
+);
+
+/// Deploys an ERC20 representation the "legacy" way: asks the Gravity contract to
+/// `CREATE` it internally. The resulting address can't be known ahead of time and a
+/// reorg or a competing sender can land a different address.
+pub async fn deploy_erc20(
+    base_denom: String,
+    erc20_name: String,
+    erc20_symbol: String,
+    erc20_decimals: u8,
+    gravity_contract_address: Address,
+    timeout: Option<Duration>,
+    eth_client: EthClient,
+) -> Result<Address, GravityError> {
+    let contract = Gravity::new(gravity_contract_address, eth_client.clone());
+    let call = contract.deploy_erc20(base_denom, erc20_name, erc20_symbol, erc20_decimals);
+    let pending_tx = call.send().await.map_err(decode_revert_reason)?;
+    let pending_tx = pending_tx.interval(Duration::from_secs(1));
+
+    let receipt = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, pending_tx)
+            .await
+            .map_err(|_| GravityError::EthereumTimeout)??,
+        None => pending_tx.await?,
+    };
+
+    erc20_address_from_deploy_receipt(receipt)
+}
+
+/// Deterministic salt for a denom's ERC20 representation; same denom always derives
+/// the same salt, and therefore the same CREATE2 address.
+pub fn create2_salt(base_denom: &str) -> [u8; 32] {
+    keccak256(base_denom.as_bytes())
+}
+
+/// The representation contract's creation bytecode followed by its ABI-encoded
+/// constructor arguments, as the EVM would build it for a direct `CREATE2`.
+fn erc20_init_code(erc20_name: &str, erc20_symbol: &str, erc20_decimals: u8) -> Bytes {
+    let constructor_args = encode(&[
+        Token::String(erc20_name.to_string()),
+        Token::String(erc20_symbol.to_string()),
+        Token::Uint(U256::from(erc20_decimals)),
+    ]);
+
+    let mut init_code = erc20_representation_bytecode().to_vec();
+    init_code.extend_from_slice(&constructor_args);
+    init_code.into()
+}
+
+/// Computes the address [`deploy_erc20_create2`] will land on before sending any
+/// transaction, using the standard CREATE2 formula.
+pub fn predict_erc20_create2_address(
+    deployer_address: Address,
+    salt: [u8; 32],
+    erc20_name: String,
+    erc20_symbol: String,
+    erc20_decimals: u8,
+) -> Address {
+    let init_code = erc20_init_code(&erc20_name, &erc20_symbol, erc20_decimals);
+    get_create2_address(deployer_address, salt, init_code)
+}
+
+/// Deploys an ERC20 representation through the CREATE2 singleton deployer living at
+/// `deployer_address`. Safe to retry after a reorg or a failed prior attempt: a
+/// second attempt with the same `base_denom` reuses the same salt, so the deployer
+/// contract just reverts instead of producing a divergent address.
+#[allow(clippy::too_many_arguments)]
+pub async fn deploy_erc20_create2(
+    deployer_address: Address,
+    salt: [u8; 32],
+    erc20_name: String,
+    erc20_symbol: String,
+    erc20_decimals: u8,
+    timeout: Option<Duration>,
+    eth_client: EthClient,
+) -> Result<Address, GravityError> {
+    let init_code = erc20_init_code(&erc20_name, &erc20_symbol, erc20_decimals);
+    let predicted = get_create2_address(deployer_address, salt, init_code.clone());
+
+    let deployer = Create2Deployer::new(deployer_address, eth_client);
+    let pending_tx = deployer
+        .deploy(salt, init_code)
+        .send()
+        .await
+        .map_err(decode_revert_reason)?;
+    let pending_tx = pending_tx.interval(Duration::from_secs(1));
+
+    match timeout {
+        Some(timeout) => {
+            tokio::time::timeout(timeout, pending_tx)
+                .await
+                .map_err(|_| GravityError::EthereumTimeout)??;
+        }
+        None => {
+            pending_tx.await?;
+        }
+    }
+
+    Ok(predicted)
+}
+
+/// The Gravity contract emits `ERC20DeployedEvent(string, address, string, string,
+/// uint8, uint256)` on a successful `deployERC20` call; the deployed address is the
+/// event's first non-indexed word.
+fn erc20_address_from_deploy_receipt(
+    receipt: Option<TransactionReceipt>,
+) -> Result<Address, GravityError> {
+    let receipt = receipt.ok_or(GravityError::EthereumTimeout)?;
+    let log = receipt.logs.first().ok_or_else(|| {
+        GravityError::EthereumContractError("no ERC20DeployedEvent emitted".to_string())
+    })?;
+    Ok(Address::from_slice(&log.data[12..32]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create2_salt_is_deterministic_per_denom() {
+        assert_eq!(create2_salt("uatom"), create2_salt("uatom"));
+        assert_ne!(create2_salt("uatom"), create2_salt("uosmo"));
+    }
+
+    #[test]
+    fn predicted_address_is_stable_for_same_inputs() {
+        let deployer: Address = "0x1111111111111111111111111111111111111111"
+            .parse()
+            .unwrap();
+        let salt = create2_salt("uatom");
+
+        let first = predict_erc20_create2_address(
+            deployer,
+            salt,
+            "Cosmos Hub Atom".to_string(),
+            "ATOM".to_string(),
+            6,
+        );
+        let second = predict_erc20_create2_address(
+            deployer,
+            salt,
+            "Cosmos Hub Atom".to_string(),
+            "ATOM".to_string(),
+            6,
+        );
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn predicted_address_changes_with_metadata() {
+        let deployer: Address = "0x1111111111111111111111111111111111111111"
+            .parse()
+            .unwrap();
+        let salt = create2_salt("uatom");
+
+        let atom = predict_erc20_create2_address(
+            deployer,
+            salt,
+            "Cosmos Hub Atom".to_string(),
+            "ATOM".to_string(),
+            6,
+        );
+        let osmo = predict_erc20_create2_address(
+            deployer,
+            salt,
+            "Osmosis".to_string(),
+            "OSMO".to_string(),
+            6,
+        );
+
+        assert_ne!(atom, osmo);
+    }
+}
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use ethers::signers::{HDPath, Ledger, LedgerError, LocalWallet, Signer, WalletError};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::transaction::eip712::Eip712;
+use ethers::types::{Address, Signature};
+use std::error::Error as StdError;
+use std::fmt;
+
+/// Wraps whichever concrete signer the configured keystore backend produces, so the
+/// rest of the relayer can build an `EthSignerMiddleware` without caring whether the
+/// private key lives in memory or on a Ledger device.
+///
+/// Not `Clone`: `Ledger` wraps a live USB/HID transport.
+#[derive(Debug)]
+pub enum EthSigner {
+    /// An in-memory key, used by the `File` and `Aws` keystore backends.
+    Local(LocalWallet),
+    /// A Ledger Nano running the Ethereum app.
+    Ledger(Ledger),
+}
+
+#[derive(Debug)]
+pub enum EthSignerError {
+    Local(WalletError),
+    Ledger(LedgerError),
+}
+
+impl fmt::Display for EthSignerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EthSignerError::Local(e) => write!(f, "{}", e),
+            EthSignerError::Ledger(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl StdError for EthSignerError {}
+
+impl From<WalletError> for EthSignerError {
+    fn from(e: WalletError) -> Self {
+        EthSignerError::Local(e)
+    }
+}
+
+impl From<LedgerError> for EthSignerError {
+    fn from(e: LedgerError) -> Self {
+        EthSignerError::Ledger(e)
+    }
+}
+
+#[async_trait]
+impl Signer for EthSigner {
+    type Error = EthSignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        match self {
+            EthSigner::Local(wallet) => Ok(wallet.sign_message(message).await?),
+            EthSigner::Ledger(ledger) => Ok(ledger.sign_message(message).await?),
+        }
+    }
+
+    async fn sign_transaction(&self, tx: &TypedTransaction) -> Result<Signature, Self::Error> {
+        match self {
+            EthSigner::Local(wallet) => Ok(wallet.sign_transaction(tx).await?),
+            EthSigner::Ledger(ledger) => Ok(ledger.sign_transaction(tx).await?),
+        }
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<Signature, Self::Error> {
+        match self {
+            EthSigner::Local(wallet) => Ok(wallet.sign_typed_data(payload).await?),
+            EthSigner::Ledger(ledger) => Ok(ledger.sign_typed_data(payload).await?),
+        }
+    }
+
+    fn address(&self) -> Address {
+        match self {
+            EthSigner::Local(wallet) => wallet.address(),
+            EthSigner::Ledger(ledger) => ledger.address(),
+        }
+    }
+
+    fn chain_id(&self) -> u64 {
+        match self {
+            EthSigner::Local(wallet) => wallet.chain_id(),
+            EthSigner::Ledger(ledger) => ledger.chain_id(),
+        }
+    }
+
+    fn with_chain_id<T: Into<u64>>(self, chain_id: T) -> Self {
+        match self {
+            EthSigner::Local(wallet) => EthSigner::Local(wallet.with_chain_id(chain_id)),
+            EthSigner::Ledger(ledger) => EthSigner::Ledger(ledger.with_chain_id(chain_id)),
+        }
+    }
+}
+
+/// Connects to a Ledger device and returns a signer for `chain_id`. An empty
+/// `derivation_path` falls back to the standard "Ledger Live" path for
+/// `account_index`; an explicit path (e.g. `m/44'/60'/0'/0/0`) always wins.
+pub async fn connect_ledger(
+    derivation_path: &str,
+    account_index: u32,
+    chain_id: u64,
+) -> Result<Ledger, LedgerError> {
+    let hd_path = if derivation_path.is_empty() {
+        HDPath::LedgerLive(account_index)
+    } else {
+        HDPath::Other(derivation_path.to_string())
+    };
+
+    Ledger::new(hd_path, chain_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_chain_id_round_trips_for_local_signer() {
+        let wallet: LocalWallet = "0000000000000000000000000000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let signer = EthSigner::Local(wallet).with_chain_id(5u64);
+
+        assert_eq!(signer.chain_id(), 5);
+    }
+
+    #[test]
+    fn local_variant_reports_its_wallet_address() {
+        let wallet: LocalWallet = "0000000000000000000000000000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let expected = wallet.address();
+        let signer = EthSigner::Local(wallet);
+
+        assert_eq!(signer.address(), expected);
+    }
+}
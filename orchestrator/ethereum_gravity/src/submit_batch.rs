@@ -1,4 +1,7 @@
-use crate::utils::{EthSignerMiddleware, GasCost, get_tx_batch_nonce, set_contract_call_gas_for_estimate};
+use crate::utils::{
+    decode_revert_reason, scale_gas_price, EthSignerMiddleware, GasCost, get_tx_batch_nonce,
+    set_contract_call_gas_for_estimate,
+};
 use ethers::contract::builders::ContractCall;
 use ethers::prelude::*;
 use ethers::types::Address as EthAddress;
@@ -7,7 +10,6 @@ use gravity_utils::error::GravityError;
 use gravity_utils::message_signatures::encode_tx_batch_confirm_hashed;
 use gravity_utils::types::*;
 use web30::types::SendTxOption;
-use std::ops::Add;
 use std::{cmp::min, time::Duration};
 use web30::{client::Web3, types::TransactionRequest};
 
@@ -36,7 +38,7 @@ pub async fn send_eth_transaction_batch(
         gravity_contract_address,
         batch.token_contract,
         eth_address,
-        eth_client,
+        eth_client.clone(),
     )
     .await?;
 
@@ -56,18 +58,30 @@ pub async fn send_eth_transaction_batch(
     }
 
     let contract_call = build_submit_batch_contract_call(
-        current_valset, batch, confirms, gravity_contract_address, gravity_id, eth_client
-    );
-    // TODO(bolten): we need to implement the gas multiplier being passed as a TxOption
-    let pending_tx = contract_call.send().await?;
-    info!("Sent batch update with txid {:#066x}", tx);
+        current_valset,
+        batch,
+        confirms,
+        gravity_contract_address,
+        gravity_id,
+        eth_client.clone(),
+    )?;
+    let contract_call = apply_gas_price_multiplier(contract_call, &options, eth_client.clone()).await?;
+
+    // no nonce is set here: on send, the nonce manager middleware in `eth_client`'s
+    // stack fetches the account nonce once, then hands out and locally increments
+    // nonces, so concurrent batch/valset submissions from this relayer no longer
+    // race each other for the same account nonce
+    let pending_tx = contract_call.send().await.map_err(decode_revert_reason)?;
+    info!("Sent batch update with txid {:#066x}", pending_tx.tx_hash());
     // TODO(bolten): ethers interval default is 7s, this mirrors what web30 was doing, should we adjust?
     // additionally we are mirroring only waiting for 1 confirmation by leaving that as default
-    pending_tx.interval(Duration::from_secs(1));
+    let pending_tx = pending_tx.interval(Duration::from_secs(1));
 
-    if let Err(tx_error) = tokio::time::timeout(timeout, async { pending_tx.await? }).await {
-        return Err(tx_error);
-    };
+    match tokio::time::timeout(timeout, pending_tx).await {
+        Ok(Ok(_)) => {}
+        Ok(Err(e)) => return Err(GravityError::from(e)),
+        Err(_) => return Err(GravityError::EthereumTimeout),
+    }
 
     let last_nonce = get_tx_batch_nonce(
         gravity_contract_address,
@@ -96,17 +110,33 @@ pub async fn estimate_tx_batch_cost(
     confirms: &[BatchConfirmResponse],
     gravity_contract_address: EthAddress,
     gravity_id: String,
+    options: Vec<SendTxOption>,
     eth_client: EthClient,
 ) -> Result<GasCost, GravityError> {
     let contract_call = build_submit_batch_contract_call(
-        current_valset, batch, confirms, gravity_contract_address, gravity_id, eth_client
-    );
-    let contract_call = set_contract_call_gas_for_estimate(contract_call, eth_client);
+        current_valset,
+        batch,
+        confirms,
+        gravity_contract_address,
+        gravity_id,
+        eth_client.clone(),
+    )?;
+    let contract_call = set_contract_call_gas_for_estimate(contract_call, eth_client.clone());
+    let contract_call = apply_gas_price_multiplier(contract_call, &options, eth_client.clone()).await?;
 
-    Ok(GasCost {
-        gas: contract_call.estimate_gas().await?,
-        gas_price
-    })
+    let gas = contract_call
+        .estimate_gas()
+        .await
+        .map_err(decode_revert_reason)?;
+    // `apply_gas_price_multiplier` only sets this when `options` asked for a
+    // multiplier; callers that just want a plain estimate fall back to whatever the
+    // gas oracle middleware would have used for a real send
+    let gas_price = match contract_call.tx.gas_price() {
+        Some(gas_price) => gas_price,
+        None => eth_client.get_gas_price().await?,
+    };
+
+    Ok(GasCost { gas, gas_price })
 }
 
 pub fn build_submit_batch_contract_call(
@@ -125,12 +155,47 @@ pub fn build_submit_batch_contract_call(
     let sig_arrays = to_arrays(sig_data);
     let (amounts, destinations, fees) = batch.get_checkpoint_values();
 
-    let contract = Gravity::new(gravity_contract_address, eth_client);
-    Ok(contract.submit_batch(
-        current_addresses, current_powers.into(), current_valset_nonce.into(),
-        sig_arrays.v, sig_arrays.r, sig_arrays.s,
-        amounts, destinations, fees,
-        new_batch_nonce.into(), batch.token_contract, batch.batch_timeout.into()
+    let contract = Gravity::new(gravity_contract_address, eth_client.clone());
+    Ok(contract
+        .submit_batch(
+            current_addresses,
+            current_powers.into(),
+            current_valset_nonce.into(),
+            sig_arrays.v,
+            sig_arrays.r,
+            sig_arrays.s,
+            amounts,
+            destinations,
+            fees,
+            new_batch_nonce.into(),
+            batch.token_contract,
+            batch.batch_timeout.into(),
+        )
         .from(eth_client.address())
-        .value(0u8.into())))
+        .value(0u8.into()))
+}
+
+/// Looks for a `SendTxOption::GasPriceMultiplier` among `options` and, if present,
+/// scales the gas price handed out by the `GasOracleMiddleware` layer of `eth_client`
+/// by that factor. Operators use this to tune fee aggressiveness (e.g. bidding above
+/// the oracle's baseline during network congestion) without touching relayer code.
+async fn apply_gas_price_multiplier(
+    contract_call: ContractCall<EthSignerMiddleware, ()>,
+    options: &[SendTxOption],
+    eth_client: EthClient,
+) -> Result<ContractCall<EthSignerMiddleware, ()>, GravityError> {
+    let multiplier = options.iter().find_map(|option| match option {
+        SendTxOption::GasPriceMultiplier(m) => Some(*m),
+        _ => None,
+    });
+
+    let multiplier = match multiplier {
+        Some(m) => m,
+        None => return Ok(contract_call),
+    };
+
+    let oracle_gas_price = eth_client.get_gas_price().await?;
+    let adjusted_gas_price = scale_gas_price(oracle_gas_price, multiplier as f64);
+
+    Ok(contract_call.gas_price(adjusted_gas_price))
 }
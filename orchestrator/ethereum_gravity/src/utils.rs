@@ -0,0 +1,193 @@
+use crate::signer::EthSigner;
+use async_trait::async_trait;
+use ethers::abi::AbiDecode;
+use ethers::contract::builders::ContractCall;
+use ethers::contract::ContractError;
+use ethers::middleware::gas_oracle::{GasCategory, GasOracle, GasOracleError, GasOracleMiddleware, ProviderOracle};
+use ethers::middleware::nonce_manager::NonceManagerMiddleware;
+use ethers::middleware::SignerMiddleware;
+use ethers::prelude::*;
+use ethers::types::{Address, U256};
+use gravity_abi::gravity::Gravity;
+use gravity_abi::gravity_errors::GravityErrors;
+use gravity_utils::error::GravityError;
+use std::sync::Arc;
+
+/// The middleware stack every outgoing relayer transaction is built through:
+/// `Provider -> GasOracleMiddleware -> NonceManagerMiddleware -> SignerMiddleware`.
+pub type EthSignerMiddleware = SignerMiddleware<
+    NonceManagerMiddleware<GasOracleMiddleware<Provider<Http>, Box<dyn GasOracle>>>,
+    EthSigner,
+>;
+
+pub type EthClient = Arc<EthSignerMiddleware>;
+
+pub struct GasCost {
+    pub gas: U256,
+    pub gas_price: U256,
+}
+
+/// A [`GasOracle`] wrapper that scales whatever price `inner` returns by a
+/// configurable factor.
+#[derive(Debug, Clone)]
+pub struct MultiplierGasOracle<O> {
+    inner: O,
+    multiplier: f64,
+}
+
+impl<O> MultiplierGasOracle<O> {
+    pub fn new(inner: O, multiplier: f64) -> Self {
+        MultiplierGasOracle { inner, multiplier }
+    }
+
+    fn scale(&self, price: U256) -> U256 {
+        scale_gas_price(price, self.multiplier)
+    }
+}
+
+#[async_trait]
+impl<O: GasOracle> GasOracle for MultiplierGasOracle<O> {
+    async fn fetch(&self) -> Result<U256, GasOracleError> {
+        self.inner.fetch().await.map(|price| self.scale(price))
+    }
+
+    async fn estimate_eip1559_fees(&self) -> Result<(U256, U256), GasOracleError> {
+        let (max_fee, max_priority_fee) = self.inner.estimate_eip1559_fees().await?;
+        Ok((self.scale(max_fee), self.scale(max_priority_fee)))
+    }
+
+    fn set_gas_category(&mut self, gas_category: GasCategory) {
+        self.inner.set_gas_category(gas_category)
+    }
+}
+
+/// Scales `price` by `multiplier`, e.g. `scale_gas_price(20 gwei, 1.2) == 24 gwei`.
+pub fn scale_gas_price(price: U256, multiplier: f64) -> U256 {
+    let multiplier_thousandths = (multiplier * 1000.0).round() as u64;
+    price * multiplier_thousandths / 1000u64
+}
+
+/// Builds the default gas oracle for the relayer: the connected node's own
+/// `eth_gasPrice`, scaled by the operator-configured multiplier.
+pub fn build_gas_oracle(provider: Provider<Http>, gas_price_multiplier: f64) -> Box<dyn GasOracle> {
+    Box::new(MultiplierGasOracle::new(
+        ProviderOracle::new(provider),
+        gas_price_multiplier,
+    ))
+}
+
+/// Assembles the full middleware stack described on [`EthSignerMiddleware`].
+pub fn build_eth_client(
+    provider: Provider<Http>,
+    wallet: EthSigner,
+    gas_oracle: Box<dyn GasOracle>,
+) -> EthClient {
+    let address = wallet.address();
+    let gas_oracle_middleware = GasOracleMiddleware::new(provider, gas_oracle);
+    let nonce_manager = NonceManagerMiddleware::new(gas_oracle_middleware, address);
+    let signer_middleware = SignerMiddleware::new(nonce_manager, wallet);
+    Arc::new(signer_middleware)
+}
+
+/// Reads the last batch nonce the Gravity contract has adopted for `erc20_contract`.
+pub async fn get_tx_batch_nonce(
+    gravity_contract_address: Address,
+    erc20_contract: Address,
+    caller_address: Address,
+    eth_client: EthClient,
+) -> Result<u64, GravityError> {
+    let contract = Gravity::new(gravity_contract_address, eth_client);
+    let nonce = contract
+        .last_batch_nonce(erc20_contract)
+        .from(caller_address)
+        .call()
+        .await?;
+    Ok(nonce.as_u64())
+}
+
+/// `estimate_gas()` reverts if the configured sender can't cover an artificially high
+/// gas limit, so cap the estimate call's limit well above any real Gravity call
+/// instead of leaving it at the provider's default.
+pub fn set_contract_call_gas_for_estimate<D: ethers::abi::Detokenize>(
+    contract_call: ContractCall<EthSignerMiddleware, D>,
+    _eth_client: EthClient,
+) -> ContractCall<EthSignerMiddleware, D> {
+    const ESTIMATE_GAS_LIMIT: u64 = 10_000_000;
+    contract_call.gas(ESTIMATE_GAS_LIMIT)
+}
+
+/// The selector `keccak256("Error(string)")[0..4]`, prepended to the ABI-encoded
+/// message whenever a Solidity `require`/`revert("...")` fires without a custom error.
+const SOLIDITY_ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Decodes raw revert data into a human-readable message, trying the standard
+/// `Error(string)` encoding first and then Gravity's own custom Solidity errors.
+/// Returns `None` when neither can make sense of `data`.
+pub fn decode_revert_bytes(data: &[u8]) -> Option<String> {
+    if data.len() > 4 && data[0..4] == SOLIDITY_ERROR_STRING_SELECTOR {
+        if let Ok(reason) = String::decode(&data[4..]) {
+            return Some(reason);
+        }
+    }
+
+    GravityErrors::decode(data).ok().map(|e| e.to_string())
+}
+
+/// Turns an opaque `ContractError` into a `GravityError::ContractRevert` carrying a
+/// decoded message, when the node actually returned revert data; falls back to the
+/// untouched error otherwise. Shared by the batch submission and ERC20 deployment
+/// call sites so a rejected transaction explains why.
+pub fn decode_revert_reason(error: ContractError<EthSignerMiddleware>) -> GravityError {
+    match error.as_revert().and_then(|data| decode_revert_bytes(data)) {
+        Some(reason) => GravityError::ContractRevert(reason),
+        None => GravityError::from(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_gas_price_applies_multiplier() {
+        let price = U256::from(20_000_000_000u64);
+        assert_eq!(scale_gas_price(price, 1.0), price);
+        assert_eq!(scale_gas_price(price, 1.5), U256::from(30_000_000_000u64));
+    }
+
+    #[test]
+    fn scale_gas_price_rounds_fractional_thousandths() {
+        let price = U256::from(1_000_000u64);
+        // 1.2345 * 1000.0 is 1234.5, which rounds half away from zero to 1235 thousandths
+        let scaled = scale_gas_price(price, 1.2345);
+        assert_eq!(scaled, U256::from(1_235 * 1_000_000u64 / 1_000));
+    }
+
+    #[test]
+    fn decodes_standard_error_string() {
+        let mut data = SOLIDITY_ERROR_STRING_SELECTOR.to_vec();
+        data.extend_from_slice(&ethers::abi::encode(&[ethers::abi::Token::String(
+            "stale batch nonce".to_string(),
+        )]));
+
+        assert_eq!(
+            decode_revert_bytes(&data),
+            Some("stale batch nonce".to_string())
+        );
+    }
+
+    #[test]
+    fn decodes_gravity_custom_error() {
+        let selector = ethers::utils::keccak256(b"BatchTimedOut()");
+
+        assert_eq!(
+            decode_revert_bytes(&selector[0..4]),
+            Some(GravityErrors::BatchTimedOut.to_string())
+        );
+    }
+
+    #[test]
+    fn returns_none_for_unrecognized_data() {
+        assert_eq!(decode_revert_bytes(&[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+}
@@ -1,12 +1,12 @@
 use crate::{application::APP, prelude::*};
 use abscissa_core::{Clap, Command, Runnable};
-use ethereum_gravity::deploy_erc20::deploy_erc20;
+use ethereum_gravity::deploy_erc20::{create2_salt, deploy_erc20, deploy_erc20_create2, predict_erc20_create2_address};
 use ethers::prelude::*;
 use gravity_proto::gravity::{DenomToErc20ParamsRequest, DenomToErc20Request};
 use gravity_utils::connection_prep::{check_for_eth, create_rpc_connections};
 use std::convert::TryFrom;
 use std::process::exit;
-use std::{sync::Arc, time::Duration};
+use std::time::Duration;
 use tokio::time::sleep as delay_for;
 
 /// Deploy Erc20
@@ -16,6 +16,11 @@ pub struct Erc20 {
 
     #[clap(short, long)]
     ethereum_key: String,
+
+    /// Deploy via the CREATE2 singleton deployer instead of a plain CREATE, so the
+    /// resulting address is known ahead of time. See `gorc deploy predict-address`.
+    #[clap(long)]
+    deterministic: bool,
 }
 
 impl Runnable for Erc20 {
@@ -36,12 +41,17 @@ impl Erc20 {
 
         let config = APP.config();
 
-        let ethereum_wallet = config.load_ethers_wallet(self.ethereum_key.clone());
+        let ethereum_wallet = config.load_ethers_wallet(self.ethereum_key.clone()).await;
         let contract_address = config
             .gravity
             .contract
             .parse()
             .expect("Could not parse gravity contract address");
+        let deployer_address = config
+            .gravity
+            .erc20_deployer
+            .parse()
+            .expect("Could not parse erc20 deployer address");
 
         let timeout = Duration::from_secs(500);
         let connections = create_rpc_connections(
@@ -53,11 +63,15 @@ impl Erc20 {
         .await;
 
         let mut grpc = connections.grpc.clone().unwrap();
-        let eth_client = SignerMiddleware::new(
+        let gas_oracle = ethereum_gravity::utils::build_gas_oracle(
             connections.eth_provider.clone().unwrap(),
-            ethereum_wallet.clone(),
+            config.ethereum.gas_price_multiplier,
+        );
+        let eth_client = ethereum_gravity::utils::build_eth_client(
+            connections.eth_provider.clone().unwrap(),
+            ethereum_wallet,
+            gas_oracle,
         );
-        let eth_client = Arc::new(eth_client);
 
         check_for_eth(eth_client.address(), eth_client.clone()).await;
 
@@ -70,22 +84,58 @@ impl Erc20 {
             .await
             .expect("Couldn't get erc-20 params")
             .into_inner();
+        let erc20_decimals = u8::try_from(res.erc20_decimals).unwrap();
 
         println!("Starting deploy of ERC20");
+        let erc20_name = res.erc20_name.clone();
+        let erc20_symbol = res.erc20_symbol.clone();
+
+        let deployed_address = if self.deterministic {
+            let salt = create2_salt(&res.base_denom);
+            let predicted = predict_erc20_create2_address(
+                deployer_address,
+                salt,
+                res.erc20_name.clone(),
+                res.erc20_symbol.clone(),
+                erc20_decimals,
+            );
+            println!("Expecting deployment to land at {:#x}", predicted);
+
+            deploy_erc20_create2(
+                deployer_address,
+                salt,
+                res.erc20_name,
+                res.erc20_symbol,
+                erc20_decimals,
+                Some(timeout),
+                eth_client.clone(),
+            )
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("Could not deploy ERC20 via CREATE2: {}", e);
+                exit(1);
+            })
+        } else {
+            deploy_erc20(
+                res.base_denom,
+                res.erc20_name,
+                res.erc20_symbol,
+                erc20_decimals,
+                contract_address,
+                Some(timeout),
+                eth_client.clone(),
+            )
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("Could not deploy ERC20: {}", e);
+                exit(1);
+            })
+        };
 
-        let res = deploy_erc20(
-            res.base_denom,
-            res.erc20_name,
-            res.erc20_symbol,
-            u8::try_from(res.erc20_decimals).unwrap(),
-            contract_address,
-            Some(timeout),
-            eth_client.clone(),
-        )
-        .await
-        .expect("Could not deploy ERC20");
-
-        println!("We have deployed ERC20 contract {}, waiting to see if the Cosmos chain choses to adopt it", res);
+        println!(
+            "We have deployed ERC20 contract {}, waiting to see if the Cosmos chain choses to adopt it",
+            deployed_address
+        );
 
         match tokio::time::timeout(Duration::from_secs(100), async {
             loop {
@@ -113,7 +163,11 @@ impl Erc20 {
             }
             Err(_) => {
                 println!(
-                    "Your ERC20 contract was not adopted, double check the metadata and try again"
+                    "Contract {} was not adopted for denom {} within the timeout. The Gravity \
+                     module rejects a new ERC20 representation whose name, symbol or decimals \
+                     don't match what was submitted (name {:?}, symbol {:?}, decimals {}) - \
+                     double check those against the token's real metadata and try again",
+                    deployed_address, denom, erc20_name, erc20_symbol, erc20_decimals
                 );
                 exit(1);
             }
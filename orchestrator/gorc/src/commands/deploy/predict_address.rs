@@ -0,0 +1,74 @@
+use crate::{application::APP, prelude::*};
+use abscissa_core::{Clap, Command, Runnable};
+use ethereum_gravity::deploy_erc20::{create2_salt, predict_erc20_create2_address};
+use gravity_proto::gravity::DenomToErc20ParamsRequest;
+use gravity_utils::connection_prep::create_rpc_connections;
+use std::convert::TryFrom;
+use std::process::exit;
+use std::time::Duration;
+
+/// Predict the CREATE2 address a `deploy erc20 --deterministic` deployment for this
+/// denom would land on, without sending any transaction.
+#[derive(Command, Debug, Clap)]
+pub struct PredictAddressCmd {
+    args: Vec<String>,
+}
+
+impl Runnable for PredictAddressCmd {
+    fn run(&self) {
+        abscissa_tokio::run_with_actix(&APP, async {
+            self.predict().await;
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            exit(1);
+        });
+    }
+}
+
+impl PredictAddressCmd {
+    async fn predict(&self) {
+        let denom = self.args.get(0).expect("denom is required");
+        let config = APP.config();
+
+        let deployer_address = config
+            .gravity
+            .erc20_deployer
+            .parse()
+            .expect("Could not parse erc20 deployer address");
+
+        let timeout = Duration::from_secs(30);
+        let connections = create_rpc_connections(
+            config.cosmos.prefix.clone(),
+            Some(config.cosmos.grpc.clone()),
+            None,
+            timeout,
+        )
+        .await;
+
+        let mut grpc = connections.grpc.clone().unwrap();
+        let req = DenomToErc20ParamsRequest {
+            denom: denom.clone(),
+        };
+        let res = grpc
+            .denom_to_erc20_params(req)
+            .await
+            .expect("Couldn't get erc-20 params")
+            .into_inner();
+
+        let salt = create2_salt(&res.base_denom);
+        let predicted = predict_erc20_create2_address(
+            deployer_address,
+            salt,
+            res.erc20_name,
+            res.erc20_symbol,
+            u8::try_from(res.erc20_decimals).unwrap(),
+        );
+
+        println!(
+            "Denom {} would deploy to {:#x} via CREATE2 deployer {:#x}",
+            denom, predicted, deployer_address
+        );
+        exit(0);
+    }
+}
@@ -2,8 +2,11 @@ use super::show::ShowEthKeyCmd;
 use crate::application::APP;
 use crate::config::Keystore;
 use abscissa_core::{clap::Parser, Application, Command, Runnable};
+use ethereum_gravity::signer::connect_ledger;
+use ethers::signers::Signer;
 use k256::pkcs8::EncodePrivateKey;
 use rand_core::OsRng;
+use std::process::exit;
 
 /// Add a new Eth Key
 #[derive(Command, Debug, Default, Parser)]
@@ -18,6 +21,18 @@ pub struct AddEthKeyCmd {
 // - [name] required; key name
 impl Runnable for AddEthKeyCmd {
     fn run(&self) {
+        abscissa_tokio::run_with_actix(&APP, async {
+            self.add().await;
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            exit(1);
+        });
+    }
+}
+
+impl AddEthKeyCmd {
+    async fn add(&self) {
         let config = APP.config();
         let keystore = &config.keystore;
 
@@ -30,13 +45,33 @@ impl Runnable for AddEthKeyCmd {
             }
         }
 
-        let mnemonic = bip32::Mnemonic::random(&mut OsRng, Default::default());
         match &config.keystore {
-            Keystore::File(_path) => {
-                eprintln!("**Important** record this bip39-mnemonic in a safe place:");
-                println!("{}", mnemonic.phrase());
-            }
-            Keystore::Aws => {}
+            Keystore::File(_path) => self.add_software_key(&name),
+            Keystore::Aws => self.add_software_key(&name),
+            Keystore::Ledger {
+                derivation_path,
+                account_index,
+                ..
+            } => self.add_ledger_key(&name, derivation_path, *account_index).await,
+        }
+
+        let show_cmd = ShowEthKeyCmd {
+            args: vec![name.to_string()],
+            show_name: false,
+        };
+        show_cmd.run();
+    }
+
+    /// Generates a fresh BIP39 mnemonic, derives a PKCS8 private key from it and
+    /// stores that key material directly in the keystore.
+    fn add_software_key(&self, name: &str) {
+        let config = APP.config();
+        let keystore = &config.keystore;
+
+        let mnemonic = bip32::Mnemonic::random(&mut OsRng, Default::default());
+        if let Keystore::File(_path) = &config.keystore {
+            eprintln!("**Important** record this bip39-mnemonic in a safe place:");
+            println!("{}", mnemonic.phrase());
         }
 
         let seed = mnemonic.to_seed("");
@@ -52,12 +87,25 @@ impl Runnable for AddEthKeyCmd {
             .to_pkcs8_der()
             .expect("Could not PKCS8 encod private key");
 
-        keystore.store(&name, &key).expect("Could not store key");
+        keystore.store(name, &key).expect("Could not store key");
+    }
 
-        let show_cmd = ShowEthKeyCmd {
-            args: vec![name.to_string()],
-            show_name: false,
-        };
-        show_cmd.run();
+    /// Asks the device which address the configured derivation path / account index
+    /// resolves to, and stores that address in the keystore. No key material ever
+    /// leaves the Ledger.
+    async fn add_ledger_key(&self, name: &str, derivation_path: &str, account_index: u32) {
+        let config = APP.config();
+        let keystore = &config.keystore;
+
+        let ledger = connect_ledger(derivation_path, account_index, config.ethereum.chain_id)
+            .await
+            .expect("Could not connect to Ledger device, is the Eth app open?");
+
+        let address = ledger.address();
+        println!("Ledger device reports address {:#x} for key {}", address, name);
+
+        keystore
+            .store(name, address.as_bytes())
+            .expect("Could not store Ledger key descriptor");
     }
 }
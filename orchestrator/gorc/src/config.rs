@@ -0,0 +1,120 @@
+use ethereum_gravity::signer::{connect_ledger, EthSigner};
+use ethers::signers::{LocalWallet, Signer};
+use k256::pkcs8::DecodePrivateKey;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Top level `gorc` configuration, loaded from `gorc.toml`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AppConfig {
+    pub cosmos: CosmosConfig,
+    pub ethereum: EthereumConfig,
+    pub gravity: GravityConfig,
+    pub keystore: Keystore,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CosmosConfig {
+    pub prefix: String,
+    pub grpc: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct EthereumConfig {
+    pub rpc: String,
+    pub chain_id: u64,
+    pub key_derivation_path: String,
+    /// Scales the gas oracle's price, e.g. `1.2` bids 20% above baseline.
+    #[serde(default = "default_gas_price_multiplier")]
+    pub gas_price_multiplier: f64,
+}
+
+fn default_gas_price_multiplier() -> f64 {
+    1.0
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GravityConfig {
+    pub contract: String,
+    /// Address of the CREATE2 singleton deployer for deterministic ERC20 deploys.
+    pub erc20_deployer: String,
+}
+
+/// Where an Eth signing key lives, and how to get at it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(tag = "backend", rename_all = "snake_case")]
+pub enum Keystore {
+    /// PKCS8-encoded private keys stored as files under `path`.
+    File(PathBuf),
+    /// Private keys stored in AWS Secrets Manager. `info` and `store` are not
+    /// implemented for this backend and return an `Unsupported` error.
+    Aws,
+    /// Signing is delegated to a Ledger's Eth app over USB; `path` only stores the
+    /// device-derived address, never key material. Empty `derivation_path` falls
+    /// back to the standard "Ledger Live" path for `account_index`.
+    Ledger {
+        path: PathBuf,
+        #[serde(default)]
+        derivation_path: String,
+        #[serde(default)]
+        account_index: u32,
+    },
+}
+
+impl Keystore {
+    /// Reads back whatever was stored under `name` by [`Keystore::store`].
+    pub fn info(&self, name: &str) -> std::io::Result<Vec<u8>> {
+        match self {
+            Keystore::File(path) | Keystore::Ledger { path, .. } => std::fs::read(path.join(name)),
+            Keystore::Aws => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "AWS keystore lookups are not implemented in this snapshot",
+            )),
+        }
+    }
+
+    /// For `File` this is PKCS8 key material; for `Ledger` it's just the
+    /// device-derived address.
+    pub fn store(&self, name: &str, data: &[u8]) -> std::io::Result<()> {
+        match self {
+            Keystore::File(path) | Keystore::Ledger { path, .. } => {
+                std::fs::create_dir_all(path)?;
+                std::fs::write(path.join(name), data)
+            }
+            Keystore::Aws => Err(std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "AWS keystore storage is not implemented in this snapshot",
+            )),
+        }
+    }
+}
+
+impl AppConfig {
+    /// Builds the signer used for every outgoing `EthSignerMiddleware`: an
+    /// in-memory wallet for `File`/`Aws`, or a Ledger-backed signer for `Ledger`.
+    pub async fn load_ethers_wallet(&self, key_name: String) -> EthSigner {
+        match &self.keystore {
+            Keystore::File(_) | Keystore::Aws => {
+                let key_bytes = self
+                    .keystore
+                    .info(&key_name)
+                    .expect("Could not load key from keystore");
+                let key = k256::SecretKey::from_pkcs8_der(&key_bytes)
+                    .expect("Could not parse PKCS8 private key");
+                let wallet = LocalWallet::from(key).with_chain_id(self.ethereum.chain_id);
+                EthSigner::Local(wallet)
+            }
+            Keystore::Ledger {
+                derivation_path,
+                account_index,
+                ..
+            } => {
+                let ledger = connect_ledger(derivation_path, *account_index, self.ethereum.chain_id)
+                    .await
+                    .expect("Could not connect to Ledger device, is the Eth app open?");
+                EthSigner::Ledger(ledger)
+            }
+        }
+    }
+}
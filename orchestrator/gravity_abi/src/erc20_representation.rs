@@ -0,0 +1,13 @@
+use ethers::types::Bytes;
+
+/// Compiled creation bytecode (no constructor args appended yet) for the minimal
+/// ERC20 representation contract Gravity deploys on Ethereum for a Cosmos denom.
+/// Compiled from `solidity/contracts/ERC20Representation.sol` by `build.rs`, the
+/// same way the `Gravity` contract bindings in this crate are generated from the
+/// Gravity ABI.
+pub fn erc20_representation_bytecode() -> Bytes {
+    Bytes::from_static(include_bytes!(concat!(
+        env!("OUT_DIR"),
+        "/erc20_representation.bin"
+    )))
+}
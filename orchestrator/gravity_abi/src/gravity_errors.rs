@@ -0,0 +1,98 @@
+use ethers::abi::{AbiError, Error as AbiParseError};
+use ethers::utils::keccak256;
+use std::fmt;
+
+/// Custom Solidity errors defined on the Gravity contract. Unlike `Error(string)`
+/// these revert with a bare 4-byte selector and no encoded message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GravityErrors {
+    InvalidValsetNonce,
+    InvalidSignature,
+    InsufficientPower,
+    InvalidBatchNonce,
+    BatchTimedOut,
+    MalformedNewValidatorSet,
+    MalformedBatch,
+    InvalidERC20Metadata,
+}
+
+impl GravityErrors {
+    const VARIANTS: &'static [(&'static str, GravityErrors)] = &[
+        ("InvalidValsetNonce()", GravityErrors::InvalidValsetNonce),
+        ("InvalidSignature()", GravityErrors::InvalidSignature),
+        ("InsufficientPower()", GravityErrors::InsufficientPower),
+        ("InvalidBatchNonce()", GravityErrors::InvalidBatchNonce),
+        ("BatchTimedOut()", GravityErrors::BatchTimedOut),
+        (
+            "MalformedNewValidatorSet()",
+            GravityErrors::MalformedNewValidatorSet,
+        ),
+        ("MalformedBatch()", GravityErrors::MalformedBatch),
+        ("InvalidERC20Metadata()", GravityErrors::InvalidERC20Metadata),
+    ];
+
+    fn selector(signature: &str) -> [u8; 4] {
+        let hash = keccak256(signature.as_bytes());
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+
+    /// Matches the leading 4-byte selector in `data` against every known error.
+    pub fn decode(data: &[u8]) -> Result<Self, AbiError> {
+        if data.len() < 4 {
+            return Err(AbiError::DecodingError(AbiParseError::InvalidData));
+        }
+        let selector = [data[0], data[1], data[2], data[3]];
+
+        Self::VARIANTS
+            .iter()
+            .find(|(signature, _)| Self::selector(signature) == selector)
+            .map(|(_, error)| *error)
+            .ok_or(AbiError::DecodingError(AbiParseError::InvalidData))
+    }
+}
+
+impl fmt::Display for GravityErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            GravityErrors::InvalidValsetNonce => "submitted valset nonce is not newer than the current one",
+            GravityErrors::InvalidSignature => "a validator signature failed to verify against the current valset",
+            GravityErrors::InsufficientPower => "signed power did not meet the required threshold",
+            GravityErrors::InvalidBatchNonce => "submitted batch nonce is not newer than the current one",
+            GravityErrors::BatchTimedOut => "batch timeout block height has already passed",
+            GravityErrors::MalformedNewValidatorSet => "new validator set arrays have mismatched lengths",
+            GravityErrors::MalformedBatch => "batch amounts/destinations/fees arrays have mismatched lengths",
+            GravityErrors::InvalidERC20Metadata => "ERC20 name, symbol or decimals did not match the expected metadata",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_every_known_selector() {
+        for (signature, expected) in GravityErrors::VARIANTS {
+            let selector = GravityErrors::selector(signature);
+            assert_eq!(GravityErrors::decode(&selector).unwrap(), *expected);
+        }
+    }
+
+    #[test]
+    fn ignores_trailing_encoded_arguments() {
+        let mut data = GravityErrors::selector("BatchTimedOut()").to_vec();
+        data.extend_from_slice(&[0u8; 32]);
+        assert_eq!(GravityErrors::decode(&data).unwrap(), GravityErrors::BatchTimedOut);
+    }
+
+    #[test]
+    fn rejects_unrecognized_selector() {
+        assert!(GravityErrors::decode(&[0xde, 0xad, 0xbe, 0xef]).is_err());
+    }
+
+    #[test]
+    fn rejects_data_shorter_than_a_selector() {
+        assert!(GravityErrors::decode(&[0x01, 0x02]).is_err());
+    }
+}
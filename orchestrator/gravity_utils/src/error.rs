@@ -0,0 +1,37 @@
+use ethers::contract::ContractError;
+use ethers::middleware::gas_oracle::GasOracleError;
+use ethers::middleware::Middleware;
+use ethers::providers::ProviderError;
+use thiserror::Error;
+
+/// Errors surfaced by the Ethereum side of the relayer, wrapping the lower-level
+/// `ethers` error types with enough context for operators to act on.
+#[derive(Debug, Error)]
+pub enum GravityError {
+    #[error("Ethereum contract error: {0}")]
+    EthereumContractError(String),
+
+    #[error("Solidity contract reverted: {0}")]
+    ContractRevert(String),
+
+    #[error("Ethereum transaction timed out")]
+    EthereumTimeout,
+
+    #[error("Ethereum provider error: {0}")]
+    EthereumProviderError(#[from] ProviderError),
+
+    #[error("Ethereum gas oracle error: {0}")]
+    GasOracleError(#[from] GasOracleError),
+
+    #[error("Invalid valset: {0}")]
+    ValidationError(String),
+
+    #[error("Invalid option: {0}")]
+    InvalidOptionsError(String),
+}
+
+impl<M: Middleware> From<ContractError<M>> for GravityError {
+    fn from(error: ContractError<M>) -> Self {
+        GravityError::EthereumContractError(error.to_string())
+    }
+}